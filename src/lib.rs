@@ -5,31 +5,167 @@ use lazy_static::lazy_static;
 use mdmodels::datamodel::DataModel;
 use proc_macro::TokenStream;
 use quote::quote;
-use std::collections::{BTreeMap, HashMap};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::{error::Error, path::Path};
-use syn::{parse_macro_input, LitStr};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, LitStr, Token};
 
 // Static variables
-const FORBIDDEN_NAMES: [&str; 9] = [
-    "type", "struct", "enum", "use", "crate", "mod", "fn", "impl", "trait",
+const RUST_KEYWORDS: [&str; 51] = [
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
 ];
 
+// Case conventions supported by serde's `rename_all` container attribute
+const SERDE_RENAME_ALL_CASES: [&str; 8] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+// Base types with no meaningful `Default` impl (there's no sensible default
+// URL or calendar date/time), unlike every other entry in `TYPE_MAPPINGS`.
+// A required field of one of these types can only be part of a struct that
+// derives/implements `Default` if the model supplies an explicit default.
+const NO_DEFAULT_BASE_TYPES: [&str; 4] = ["date", "datetime", "uri", "url"];
+
 // Lazy static initialization for type mappings
 lazy_static! {
     static ref TYPE_MAPPINGS: HashMap<&'static str, &'static str> = {
         let mut m = HashMap::new();
-        m.insert("integer", "i32");
-        m.insert("float", "f32");
+        m.insert("integer", "i64");
+        m.insert("float", "f64");
+        m.insert("number", "f64");
         m.insert("string", "String");
         m.insert("boolean", "bool");
+        m.insert("date", "chrono::NaiveDate");
+        m.insert("datetime", "chrono::DateTime<chrono::Utc>");
+        m.insert("decimal", "rust_decimal::Decimal");
+        m.insert("bytes", "Vec<u8>");
+        m.insert("uri", "url::Url");
+        m.insert("url", "url::Url");
         m
     };
 }
 
+/// A front-end-agnostic description of a single struct field, produced by
+/// either the markdown or the JSON Schema front end and consumed by the
+/// shared struct generator.
+struct AttrSpec {
+    name: String,
+    dtypes: Vec<String>,
+    is_array: bool,
+    required: bool,
+    default: Option<String>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    pattern: Option<String>,
+}
+
+/// A front-end-agnostic description of a generated struct.
+struct ObjectSpec {
+    name: String,
+    attributes: Vec<AttrSpec>,
+}
+
+/// A front-end-agnostic description of a generated enum: variant name mapped
+/// to the string value it serializes to.
+struct EnumSpec {
+    name: String,
+    mappings: BTreeMap<String, String>,
+}
+
+/// A single `"model-type": "rust::path"` entry inside a `types = { ... }`
+/// macro argument.
+struct TypeOverrideEntry {
+    key: LitStr,
+    value: LitStr,
+}
+
+impl Parse for TypeOverrideEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: LitStr = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let value: LitStr = input.parse()?;
+        Ok(TypeOverrideEntry { key, value })
+    }
+}
+
+/// Input to the `parse_mdmodel!`/`parse_jsonschema!` macros: the model path,
+/// plus optional `key = value` arguments such as `rename_all = "camelCase"`
+/// and `types = { "mass": "uom::si::f64::Mass" }`.
+struct MacroInput {
+    path: LitStr,
+    rename_all: Option<LitStr>,
+    types: HashMap<String, String>,
+}
+
+impl Parse for MacroInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: LitStr = input.parse()?;
+        let mut rename_all = None;
+        let mut types = HashMap::new();
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            let key: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            if key == "rename_all" {
+                let value: LitStr = input.parse()?;
+                if !SERDE_RENAME_ALL_CASES.contains(&value.value().as_str()) {
+                    return Err(syn::Error::new(
+                        value.span(),
+                        format!(
+                            "Unknown rename_all case '{}', expected one of: {}",
+                            value.value(),
+                            SERDE_RENAME_ALL_CASES.join(", ")
+                        ),
+                    ));
+                }
+                rename_all = Some(value);
+            } else if key == "types" {
+                let content;
+                syn::braced!(content in input);
+                let entries = content.parse_terminated(TypeOverrideEntry::parse, Token![,])?;
+                for entry in entries {
+                    types.insert(entry.key.value(), entry.value.value());
+                }
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Unknown parse_mdmodel argument: {}", key),
+                ));
+            }
+        }
+
+        Ok(MacroInput {
+            path,
+            rename_all,
+            types,
+        })
+    }
+}
+
 /// Procedural macro to generate structs from markdown models
 ///
 /// # Arguments
-/// * `input` - A TokenStream representing the input markdown file path
+/// * `input` - A TokenStream representing the input markdown file path,
+///   optionally followed by `rename_all = "case"` to apply a
+///   `#[serde(rename_all = "...")]` to every generated struct
 ///
 /// # Returns
 /// A TokenStream containing the generated Rust code for the structs and enums
@@ -41,94 +177,154 @@ pub fn parse_mdmodel(input: TokenStream) -> TokenStream {
         |s| Path::new(&s).to_path_buf(),
     );
 
-    // Parse the input TokenStream as a literal string
-    let input = parse_macro_input!(input as LitStr).value();
-    let path = dir.join(input);
+    let input = parse_macro_input!(input as MacroInput);
+    let path = dir.join(input.path.value());
 
     // Parse the DataModel from the specified path
     let model = DataModel::from_markdown(&path)
         .unwrap_or_else(|_| panic!("Failed to parse the markdown model at path: {:?}", path));
-    let model_name = syn::Ident::new(
-        &to_snake(model.name.unwrap_or("model".to_string())),
-        proc_macro2::Span::call_site(),
+    let model_name = to_snake(model.name.unwrap_or("model".to_string()));
+
+    let objects = model
+        .objects
+        .into_iter()
+        .map(|object| ObjectSpec {
+            name: object.name,
+            attributes: object
+                .attributes
+                .into_iter()
+                .map(|attribute| AttrSpec {
+                    name: attribute.name,
+                    dtypes: attribute.dtypes,
+                    is_array: attribute.is_array,
+                    required: attribute.required,
+                    default: attribute.default,
+                    minimum: attribute.minimum,
+                    maximum: attribute.maximum,
+                    min_length: attribute.min_length,
+                    max_length: attribute.max_length,
+                    min_items: attribute.min_items,
+                    max_items: attribute.max_items,
+                    pattern: attribute.pattern,
+                })
+                .collect(),
+        })
+        .collect();
+    let enums = model
+        .enums
+        .into_iter()
+        .map(|enum_| EnumSpec {
+            name: enum_.name,
+            mappings: enum_.mappings,
+        })
+        .collect();
+
+    generate_module(&model_name, objects, enums, input.rename_all, &input.types)
+}
+
+/// Procedural macro to generate structs from a JSON Schema (Draft-07 or
+/// 2020-12) file, converging on the same generator `parse_mdmodel!` uses.
+///
+/// # Arguments
+/// * `input` - A TokenStream representing the input JSON Schema file path,
+///   optionally followed by `rename_all = "case"` to apply a
+///   `#[serde(rename_all = "...")]` to every generated struct, and/or
+///   `types = { "model-type": "rust::path" }` to register extra type mappings
+///
+/// # Returns
+/// A TokenStream containing the generated Rust code for the structs and enums
+#[proc_macro]
+pub fn parse_jsonschema(input: TokenStream) -> TokenStream {
+    let dir = std::env::var("CARGO_MANIFEST_DIR").map_or_else(
+        |_| std::env::current_dir().unwrap(),
+        |s| Path::new(&s).to_path_buf(),
     );
-    let mut structs = vec![];
-
-    // Iterate through the objects in the model
-    for object in model.objects {
-        if is_reserved(&object.name) {
-            panic!("Reserved keyword used as object name: {}", object.name);
-        }
-
-        let struct_name = syn::Ident::new(&object.name, proc_macro2::Span::call_site());
-        let mut fields = vec![];
-        let mut getters = vec![];
-        let mut setters = vec![];
-
-        // Iterate through the attributes of each object
-        for attribute in object.attributes {
-            let field_name = syn::Ident::new(&attribute.name, proc_macro2::Span::call_site());
-            let field_type = get_data_type(&attribute.dtypes[0])
-                .unwrap_or_else(|_| panic!("Unknown data type: {}", attribute.dtypes[0]));
-            let wrapped_type = wrap_dtype(attribute.is_array, attribute.required, field_type);
-            let builder_attr =
-                get_builder_attr(attribute.is_array, attribute.required, &attribute.name);
-            let serde_attr = get_serde_attr(attribute.is_array, attribute.required);
-
-            fields.push(quote! {
-                #builder_attr
-                #serde_attr
-                pub #field_name: #wrapped_type
-            });
 
-            let getter_name = syn::Ident::new(
-                format!("get_{}", attribute.name).as_str(),
-                proc_macro2::Span::call_site(),
-            );
+    let input = parse_macro_input!(input as MacroInput);
+    let path = dir.join(input.path.value());
 
-            let setter_name = syn::Ident::new(
-                format!("set_{}", attribute.name).as_str(),
-                proc_macro2::Span::call_site(),
-            );
+    let raw = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Failed to read the JSON Schema at path: {:?}", path));
+    let schema: serde_json::Value = serde_json::from_str(&raw)
+        .unwrap_or_else(|_| panic!("Failed to parse the JSON Schema at path: {:?}", path));
 
-            getters.push(quote! {
-                pub fn #getter_name(&self) -> &#wrapped_type {
-                    &self.#field_name
-                }
-            });
+    let model_name = to_snake(
+        schema
+            .get("title")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("model")
+            .to_string(),
+    );
 
-            setters.push(quote! {
-                pub fn #setter_name(&mut self, value: #wrapped_type) -> &mut Self {
-                    self.#field_name = value;
-                    self
-                }
-            });
-        }
+    let (objects, enums) = jsonschema_to_specs(&schema);
 
-        // Generate the struct definition with pyclass and constructor
-        let struct_def = quote! {
-            #[derive(Builder, Debug, Clone, Default, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
-            pub struct #struct_name {
-                #(#fields),*
-            }
+    generate_module(&model_name, objects, enums, input.rename_all, &input.types)
+}
 
-            impl #struct_name {
-                #(#getters)*
-                #(#setters)*
-            }
-        };
+/// Shared backend for both `parse_mdmodel!` and `parse_jsonschema!`: turns
+/// front-end-agnostic object/enum specs into the generated module.
+fn generate_module(
+    model_name: &str,
+    objects: Vec<ObjectSpec>,
+    enums: Vec<EnumSpec>,
+    rename_all: Option<LitStr>,
+    type_overrides: &HashMap<String, String>,
+) -> TokenStream {
+    let model_name = syn::Ident::new(model_name, proc_macro2::Span::call_site());
+    let rename_all_attr = rename_all.map(|case| {
+        quote! { #[serde(rename_all = #case)] }
+    });
+    let uses_bytes = objects
+        .iter()
+        .flat_map(|object| &object.attributes)
+        .any(|attribute| {
+            attribute.required
+                && !attribute.is_array
+                && attribute.dtypes.first().map(String::as_str) == Some("bytes")
+        });
 
-        structs.push(struct_def);
-    }
+    let structs: Vec<_> = objects
+        .iter()
+        .map(|object| build_struct(object, &rename_all_attr, type_overrides))
+        .collect();
 
-    // Iterate through enumerations
-    let mut enums = vec![];
-    for enum_ in model.enums {
-        if is_reserved(&enum_.name) {
-            panic!("Reserved keyword used as enum name: {}", enum_.name);
+    let enums: Vec<_> = enums
+        .iter()
+        .map(|enum_| {
+            let enum_name = to_ident(&enum_.name).0;
+            generate_enum(&enum_.mappings, &enum_name)
+        })
+        .collect();
+
+    // Only emit the base64 (de)serialization helper when some field actually
+    // needs it, since otherwise it would sit unused.
+    let base64_serde_mod = if uses_bytes {
+        quote! {
+            /// `#[serde(with = "base64_serde")]` helper for `bytes` fields, which are
+            /// represented as `Vec<u8>` but transmitted as base64-encoded strings.
+            pub mod base64_serde {
+                use base64::{engine::general_purpose::STANDARD, Engine as _};
+                use serde::{Deserialize, Deserializer, Serializer};
+
+                pub fn serialize<S>(bytes: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.serialize_str(&STANDARD.encode(bytes))
+                }
+
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let encoded = String::deserialize(deserializer)?;
+                    STANDARD.decode(&encoded).map_err(serde::de::Error::custom)
+                }
+            }
         }
-        enums.push(generate_enum(&enum_.mappings, &enum_.name))
-    }
+    } else {
+        quote! {}
+    };
 
     // Combine all generated structs into a single TokenStream
     let expanded = quote! {
@@ -136,6 +332,34 @@ pub fn parse_mdmodel(input: TokenStream) -> TokenStream {
             use derive_builder::Builder;
             use std::error::Error;
 
+            /// A single constraint violation reported by a generated `validate()` method.
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct ValidationError {
+                pub field: String,
+                pub message: String,
+            }
+
+            impl std::fmt::Display for ValidationError {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}: {}", self.field, self.message)
+                }
+            }
+
+            /// Returned by a generated enum's `FromStr`/`TryFrom<&str>` impls when
+            /// the input matches none of its variants.
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct UnknownVariant(pub String);
+
+            impl std::fmt::Display for UnknownVariant {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "unknown variant: {}", self.0)
+                }
+            }
+
+            impl std::error::Error for UnknownVariant {}
+
+            #base64_serde_mod
+
             #(#structs)*
             #(#enums)*
         }
@@ -144,6 +368,198 @@ pub fn parse_mdmodel(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Generates a single struct (fields, getters/setters, `new()`, `validate()`)
+/// from a front-end-agnostic [`ObjectSpec`].
+fn build_struct(
+    object: &ObjectSpec,
+    rename_all_attr: &Option<proc_macro2::TokenStream>,
+    type_overrides: &HashMap<String, String>,
+) -> proc_macro2::TokenStream {
+    let struct_name = to_ident(&object.name).0;
+    let mut fields = vec![];
+    let mut getters = vec![];
+    let mut setters = vec![];
+    let mut validations = vec![];
+    let mut ctor_params = vec![];
+    let mut ctor_assignments = vec![];
+    let mut default_assignments = vec![];
+    let mut has_custom_default = false;
+    // Whether the struct as a whole can derive/implement `Default` at all.
+    // A required, non-array field whose base type has no `Default` impl
+    // (e.g. `url::Url`, `chrono::NaiveDate`) and no model-declared default
+    // rules this out entirely, since there's no value to fill it with.
+    let mut can_default = true;
+
+    // Iterate through the attributes of the object
+    for attribute in &object.attributes {
+        let (field_name, field_base) = to_ident(&attribute.name);
+        let field_type = get_data_type(&attribute.dtypes[0], type_overrides)
+            .unwrap_or_else(|_| panic!("Unknown data type: {}", attribute.dtypes[0]));
+        let wrapped_type = wrap_dtype(attribute.is_array, attribute.required, field_type);
+
+        let default_expr: Option<syn::Expr> = attribute.default.as_deref().map(|expr| {
+            syn::parse_str(expr)
+                .unwrap_or_else(|_| panic!("Invalid default expression for field {}: {}", attribute.name, expr))
+        });
+        if default_expr.is_some() {
+            has_custom_default = true;
+        }
+
+        // Option<T>/Vec<T> are always Default regardless of T, so only a
+        // required scalar field of a no-Default base type without its own
+        // model default is a problem.
+        let field_has_default = default_expr.is_some()
+            || !attribute.required
+            || attribute.is_array
+            || !NO_DEFAULT_BASE_TYPES.contains(&attribute.dtypes[0].as_str());
+        if !field_has_default {
+            can_default = false;
+        }
+
+        let builder_attr = get_builder_attr(
+            attribute.is_array,
+            attribute.required,
+            &field_base,
+            attribute.default.as_deref(),
+            field_has_default,
+        );
+        let serde_attr = get_serde_attr(
+            attribute.is_array,
+            attribute.required,
+            &attribute.name,
+            &field_base,
+            &attribute.dtypes[0],
+        );
+
+        fields.push(quote! {
+            #builder_attr
+            #serde_attr
+            pub #field_name: #wrapped_type
+        });
+
+        let default_value = match &default_expr {
+            Some(expr) => quote! { #expr },
+            None => quote! { Default::default() },
+        };
+        default_assignments.push(quote! { #field_name: #default_value });
+
+        if attribute.required && !attribute.is_array {
+            ctor_params.push(quote! { #field_name: #wrapped_type });
+            ctor_assignments.push(quote! { #field_name });
+        } else {
+            ctor_assignments.push(quote! { #field_name: #default_value });
+        }
+
+        let getter_name = syn::Ident::new(
+            format!("get_{}", field_base).as_str(),
+            proc_macro2::Span::call_site(),
+        );
+
+        let setter_name = syn::Ident::new(
+            format!("set_{}", field_base).as_str(),
+            proc_macro2::Span::call_site(),
+        );
+
+        getters.push(quote! {
+            pub fn #getter_name(&self) -> &#wrapped_type {
+                &self.#field_name
+            }
+        });
+
+        setters.push(quote! {
+            pub fn #setter_name(&mut self, value: #wrapped_type) -> &mut Self {
+                self.#field_name = value;
+                self
+            }
+        });
+
+        validations.push(get_validation_checks(
+            &field_name,
+            &attribute.name,
+            attribute.is_array,
+            attribute.required,
+            &attribute.dtypes[0],
+            attribute.minimum,
+            attribute.maximum,
+            attribute.min_length,
+            attribute.max_length,
+            attribute.min_items,
+            attribute.max_items,
+            attribute.pattern.as_deref(),
+            type_overrides,
+        ));
+    }
+
+    // A custom default literal/expression on any field means the struct can no
+    // longer rely on `#[derive(Default)]`; it gets a hand-written impl instead
+    // (unless some other required field has no default at all, see below).
+    let mut derives = vec![
+        quote! { Builder },
+        quote! { Debug },
+        quote! { Clone },
+        quote! { serde::Serialize },
+        quote! { serde::Deserialize },
+        quote! { schemars::JsonSchema },
+    ];
+    let default_impl = if !can_default {
+        // At least one required field has no usable default value (no
+        // model default, and its base type isn't `Default`), so the struct
+        // can't derive or implement `Default` at all.
+        quote! {}
+    } else if has_custom_default {
+        quote! {
+            impl Default for #struct_name {
+                fn default() -> Self {
+                    Self {
+                        #(#default_assignments),*
+                    }
+                }
+            }
+        }
+    } else {
+        derives.push(quote! { Default });
+        quote! {}
+    };
+
+    // Generate the struct definition with its constructor, accessors and validate()
+    quote! {
+        #[derive(#(#derives),*)]
+        #rename_all_attr
+        pub struct #struct_name {
+            #(#fields),*
+        }
+
+        #default_impl
+
+        impl #struct_name {
+            /// Creates a new instance from its required fields, filling every
+            /// optional or array field with its default value.
+            pub fn new(#(#ctor_params),*) -> Self {
+                Self {
+                    #(#ctor_assignments),*
+                }
+            }
+
+            #(#getters)*
+            #(#setters)*
+
+            /// Checks every attribute against the constraints declared in the
+            /// source model, collecting all violations instead of stopping at
+            /// the first one.
+            pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+                let mut errors: Vec<ValidationError> = Vec::new();
+                #(#validations)*
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        }
+    }
+}
+
 /// Enumeration for data types
 enum DataTypes {
     BaseType(syn::Type),
@@ -154,10 +570,20 @@ enum DataTypes {
 ///
 /// # Arguments
 /// * `dtype` - A string slice representing the data type
+/// * `type_overrides` - Extra `model-type -> rust-path` mappings registered via
+///   the macro's `types = { ... }` argument, checked before the built-in table
 ///
 /// # Returns
 /// A Result containing either a DataTypes enum or an error
-fn get_data_type(dtype: &str) -> Result<DataTypes, Box<dyn Error>> {
+fn get_data_type(
+    dtype: &str,
+    type_overrides: &HashMap<String, String>,
+) -> Result<DataTypes, Box<dyn Error>> {
+    if let Some(t) = type_overrides.get(dtype) {
+        let field_type: syn::Type = syn::parse_str(t)?;
+        return Ok(DataTypes::BaseType(field_type));
+    }
+
     match TYPE_MAPPINGS.get(dtype) {
         Some(t) => {
             let field_type: syn::Type = syn::parse_str(t)?;
@@ -210,10 +636,21 @@ fn wrap_dtype(is_array: bool, required: bool, dtype: DataTypes) -> proc_macro2::
 /// * `is_array` - A boolean indicating if the field is an array
 /// * `required` - A boolean indicating if the field is required
 /// * `name` - A string slice representing the field name
+/// * `default` - A Rust expression source declared as the field's default in the model, if any
+/// * `has_default` - Whether the field has *some* usable default value (a
+///   model-declared expression, or a base type that implements `Default`).
+///   When `false`, no `#[builder(default...)]` is emitted at all, since
+///   `derive_builder` would otherwise require `FieldType: Default`.
 ///
 /// # Returns
 /// A TokenStream representing the builder attributes
-fn get_builder_attr(is_array: bool, required: bool, name: &str) -> proc_macro2::TokenStream {
+fn get_builder_attr(
+    is_array: bool,
+    required: bool,
+    name: &str,
+    default: Option<&str>,
+    has_default: bool,
+) -> proc_macro2::TokenStream {
     let mut setter_args = vec![];
 
     if !required {
@@ -227,8 +664,19 @@ fn get_builder_attr(is_array: bool, required: bool, name: &str) -> proc_macro2::
 
     let setter_args = quote! { #(#setter_args),* };
 
+    let mut builder_args = vec![];
+    match default {
+        Some(expr) => {
+            let expr = syn::LitStr::new(expr, proc_macro2::Span::call_site());
+            builder_args.push(quote! { default = #expr });
+        }
+        None if has_default => builder_args.push(quote! { default }),
+        None => {}
+    }
+    builder_args.push(quote! { setter(into, #setter_args) });
+
     quote! {
-        #[builder(default, setter(into, #setter_args))]
+        #[builder(#(#builder_args),*)]
     }
 }
 
@@ -237,16 +685,224 @@ fn get_builder_attr(is_array: bool, required: bool, name: &str) -> proc_macro2::
 /// # Arguments
 /// * `is_array` - A boolean indicating if the field is an array
 /// * `required` - A boolean indicating if the field is required
+/// * `original_name` - The attribute name as declared in the model
+/// * `field_base` - The sanitized name the field ident is based on, without any `r#` prefix
 ///
 /// # Returns
 /// A TokenStream representing the serde attributes
-fn get_serde_attr(is_array: bool, required: bool) -> proc_macro2::TokenStream {
+fn get_serde_attr(
+    is_array: bool,
+    required: bool,
+    original_name: &str,
+    field_base: &str,
+    dtype: &str,
+) -> proc_macro2::TokenStream {
+    let mut attrs = vec![];
+
+    if original_name != field_base {
+        let rename = syn::LitStr::new(original_name, proc_macro2::Span::call_site());
+        attrs.push(quote! { rename = #rename });
+    }
+
     if !required && !is_array {
-        quote! { #[serde(skip_serializing_if = "Option::is_none")] }
+        attrs.push(quote! { skip_serializing_if = "Option::is_none" });
     } else if is_array {
-        quote! { #[serde(default)] }
-    } else {
+        attrs.push(quote! { default });
+    }
+
+    // `bytes` fields are `Vec<u8>` in Rust but travel as base64 strings on the
+    // wire; only the plain required/non-array shape is supported so far.
+    if dtype == "bytes" && required && !is_array {
+        attrs.push(quote! { with = "base64_serde" });
+    }
+
+    if attrs.is_empty() {
         quote! {}
+    } else {
+        quote! { #[serde(#(#attrs),*)] }
+    }
+}
+
+/// Builds the constraint checks for a single field, to be spliced into the
+/// body of the generated `validate()` method where an `errors: Vec<ValidationError>`
+/// is already in scope.
+///
+/// # Arguments
+/// * `field_name` - The generated field identifier
+/// * `original_name` - The attribute name as declared in the model, used in error messages
+/// * `is_array` - Whether the field is a `Vec`
+/// * `required` - Whether the attribute is required in the model
+/// * `dtype` - The attribute's declared model data type (e.g. `"string"`)
+/// * `minimum` / `maximum` - Numeric bounds from the model, if any
+/// * `min_length` / `max_length` - String length bounds from the model, if any
+/// * `min_items` / `max_items` - Array item-count bounds from the model, if any
+/// * `pattern` - A regex the string value must match, if any
+/// * `type_overrides` - Extra `model-type -> rust-path` mappings registered via
+///   the macro's `types = { ... }` argument; a dtype resolved through this map
+///   is a scalar, not a generated complex type, same as in `get_data_type`
+///
+/// # Returns
+/// A TokenStream of statements appending to `errors` for this field
+#[allow(clippy::too_many_arguments)]
+fn get_validation_checks(
+    field_name: &syn::Ident,
+    original_name: &str,
+    is_array: bool,
+    required: bool,
+    dtype: &str,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    pattern: Option<&str>,
+    type_overrides: &HashMap<String, String>,
+) -> proc_macro2::TokenStream {
+    let is_numeric = matches!(dtype, "integer" | "float" | "number");
+    let is_decimal = dtype == "decimal";
+    let is_string = dtype == "string";
+    // Mirrors get_data_type's resolution order: a dtype satisfied by an
+    // override or the built-in table is a scalar, never a generated struct.
+    let is_complex = !type_overrides.contains_key(dtype) && !TYPE_MAPPINGS.contains_key(dtype);
+    let mut checks = vec![];
+
+    if is_array {
+        if let Some(min_items) = min_items {
+            checks.push(quote! {
+                if value.len() < #min_items {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("expected at least {} items, got {}", #min_items, value.len()),
+                    });
+                }
+            });
+        }
+        if let Some(max_items) = max_items {
+            checks.push(quote! {
+                if value.len() > #max_items {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("expected at most {} items, got {}", #max_items, value.len()),
+                    });
+                }
+            });
+        }
+        if is_complex {
+            checks.push(quote! {
+                for item in value.iter() {
+                    if let Err(item_errors) = item.validate() {
+                        errors.extend(item_errors);
+                    }
+                }
+            });
+        }
+    } else if is_numeric {
+        if let Some(minimum) = minimum {
+            checks.push(quote! {
+                if (*value as f64) < #minimum {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("{} is below the minimum of {}", value, #minimum),
+                    });
+                }
+            });
+        }
+        if let Some(maximum) = maximum {
+            checks.push(quote! {
+                if (*value as f64) > #maximum {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("{} is above the maximum of {}", value, #maximum),
+                    });
+                }
+            });
+        }
+    } else if is_decimal {
+        // `Decimal` doesn't support `as f64`; go through `ToPrimitive` instead.
+        if let Some(minimum) = minimum {
+            checks.push(quote! {
+                if rust_decimal::prelude::ToPrimitive::to_f64(value).is_some_and(|v| v < #minimum) {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("{} is below the minimum of {}", value, #minimum),
+                    });
+                }
+            });
+        }
+        if let Some(maximum) = maximum {
+            checks.push(quote! {
+                if rust_decimal::prelude::ToPrimitive::to_f64(value).is_some_and(|v| v > #maximum) {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("{} is above the maximum of {}", value, #maximum),
+                    });
+                }
+            });
+        }
+    } else if is_string {
+        if let Some(min_length) = min_length {
+            checks.push(quote! {
+                if value.chars().count() < #min_length {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("expected at least {} characters, got {}", #min_length, value.chars().count()),
+                    });
+                }
+            });
+        }
+        if let Some(max_length) = max_length {
+            checks.push(quote! {
+                if value.chars().count() > #max_length {
+                    errors.push(ValidationError {
+                        field: #original_name.to_string(),
+                        message: format!("expected at most {} characters, got {}", #max_length, value.chars().count()),
+                    });
+                }
+            });
+        }
+        if let Some(pattern) = pattern {
+            checks.push(quote! {
+                {
+                    static PATTERN: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+                        regex::Regex::new(#pattern).expect("invalid regex pattern in model")
+                    });
+                    if !PATTERN.is_match(value) {
+                        errors.push(ValidationError {
+                            field: #original_name.to_string(),
+                            message: format!("'{}' does not match pattern {}", value, #pattern),
+                        });
+                    }
+                }
+            });
+        }
+    } else if is_complex {
+        checks.push(quote! {
+            if let Err(item_errors) = value.validate() {
+                errors.extend(item_errors);
+            }
+        });
+    }
+
+    if checks.is_empty() {
+        return quote! {};
+    }
+
+    // `wrap_dtype` never wraps an array of complex types in `Option`, so such a
+    // field is a plain `Vec` even when the model marks it optional.
+    if required || (is_array && is_complex) {
+        quote! {
+            {
+                let value = &self.#field_name;
+                #(#checks)*
+            }
+        }
+    } else {
+        quote! {
+            if let Some(value) = self.#field_name.as_ref() {
+                #(#checks)*
+            }
+        }
     }
 }
 
@@ -258,15 +914,19 @@ fn get_serde_attr(is_array: bool, required: bool) -> proc_macro2::TokenStream {
 ///
 /// # Returns
 /// A TokenStream containing the generated enum code
-fn generate_enum(mappings: &BTreeMap<String, String>, name: &str) -> proc_macro2::TokenStream {
-    let enum_name = syn::Ident::new(name, proc_macro2::Span::call_site());
+fn generate_enum(
+    mappings: &BTreeMap<String, String>,
+    enum_name: &syn::Ident,
+) -> proc_macro2::TokenStream {
     let mut variants = vec![];
     let mut values = vec![];
+    let mut from_str_arms = vec![];
     let mut index = 0;
 
     for (key, value) in mappings {
         let variant_name = syn::Ident::new(&to_camel(key), proc_macro2::Span::call_site());
         let variant_value = syn::LitStr::new(value, proc_macro2::Span::call_site());
+        let camel_name = to_camel(key);
 
         if index == 0 {
             variants.push(quote! {
@@ -283,6 +943,15 @@ fn generate_enum(mappings: &BTreeMap<String, String>, name: &str) -> proc_macro2
         values.push(quote! {
             #enum_name::#variant_name => #variant_value.to_string()
         });
+
+        // Accept both the model's mapped value and, as a fallback, the
+        // variant's camel-cased name, so `"value"` and `"Value"` both parse.
+        from_str_arms.push(if camel_name == *value {
+            quote! { #variant_value => Ok(#enum_name::#variant_name), }
+        } else {
+            let camel_value = syn::LitStr::new(&camel_name, proc_macro2::Span::call_site());
+            quote! { #variant_value | #camel_value => Ok(#enum_name::#variant_name), }
+        });
     }
 
     quote! {
@@ -299,12 +968,96 @@ fn generate_enum(mappings: &BTreeMap<String, String>, name: &str) -> proc_macro2
                 write!(f, "{}", s)
             }
         }
+
+        impl std::str::FromStr for #enum_name {
+            type Err = UnknownVariant;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    #(#from_str_arms)*
+                    other => Err(UnknownVariant(other.to_string())),
+                }
+            }
+        }
+
+        impl TryFrom<&str> for #enum_name {
+            type Error = UnknownVariant;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl #enum_name {
+            /// Enum membership is already enforced by the type system, so there
+            /// are no further constraints to check; this only exists so that
+            /// object fields referencing an enum can call `validate()` uniformly.
+            pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+                Ok(())
+            }
+        }
     }
 }
 
-/// Checks if an object or enum name is a reserved keyword
+/// Checks if a name is a reserved Rust keyword
 fn is_reserved(name: &str) -> bool {
-    FORBIDDEN_NAMES.contains(&name)
+    RUST_KEYWORDS.contains(&name)
+}
+
+/// Replaces characters that are illegal in a Rust identifier (`-`, `.`,
+/// whitespace) with `_` and prefixes a leading digit, without touching
+/// keyword collisions.
+fn sanitize_base(name: &str) -> String {
+    let mut base: String = name
+        .chars()
+        .map(|c| if c == '-' || c == '.' || c.is_whitespace() {
+            '_'
+        } else {
+            c
+        })
+        .collect();
+
+    if base.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        base = format!("_{base}");
+    }
+
+    base
+}
+
+/// Turns a model object/attribute name into a legal Rust identifier.
+///
+/// Names that contain characters illegal in an identifier, start with a
+/// digit, or collide with a Rust keyword are sanitized (e.g. `sample-id` ->
+/// `sample_id`, `type` -> `r#type`). The original model name is not lost:
+/// callers can compare it against the returned base name to know whether a
+/// `#[serde(rename = "...")]` is needed to keep the wire format unchanged.
+///
+/// # Returns
+/// A tuple of the generated `syn::Ident` and the base name (without any `r#`
+/// prefix) it was derived from (used to build derived identifiers like getters/setters).
+fn to_ident(name: &str) -> (syn::Ident, String) {
+    let base = sanitize_base(name);
+    // `self`/`Self`/`super`/`crate` can't be used as raw identifiers (`r#self`
+    // is still rejected by rustc), so they're suffixed instead of escaped.
+    // `field_base` must reflect that suffix, not the pre-suffix `base`,
+    // otherwise `get_serde_attr`'s rename check can't tell the field was
+    // renamed at all.
+    let (ident_string, field_base) = if is_reserved(&base) {
+        match base.as_str() {
+            "self" | "Self" | "super" | "crate" => {
+                let suffixed = format!("{base}_");
+                (suffixed.clone(), suffixed)
+            }
+            other => (format!("r#{other}"), other.to_string()),
+        }
+    } else {
+        (base.clone(), base)
+    };
+
+    let ident = syn::parse_str(&ident_string)
+        .unwrap_or_else(|_| panic!("Failed to build a valid Rust identifier from: {}", name));
+
+    (ident, field_base)
 }
 
 /// Function to convert a string to snake case
@@ -316,3 +1069,152 @@ fn to_snake(name: String) -> String {
 fn to_camel(name: &str) -> String {
     name.to_case(Case::UpperCamel)
 }
+
+/// Walks a JSON Schema's top-level `definitions`/`$defs` (if any) plus the
+/// root schema node itself, turning each into an [`ObjectSpec`] or
+/// [`EnumSpec`], mirroring what `DataModel::from_markdown` produces for the
+/// markdown front end.
+fn jsonschema_to_specs(schema: &Value) -> (Vec<ObjectSpec>, Vec<EnumSpec>) {
+    let mut objects = vec![];
+    let mut enums = vec![];
+
+    // The common hand-written case is a single root object with its own
+    // `properties` and no `definitions`/`$defs` at all, so the root node
+    // needs converting regardless of whether nested definitions exist.
+    if schema.get("type").and_then(Value::as_str) == Some("object") {
+        let name = schema
+            .get("title")
+            .and_then(Value::as_str)
+            .unwrap_or("Model")
+            .to_string();
+        objects.push(ObjectSpec {
+            name,
+            attributes: object_attributes(schema),
+        });
+    }
+
+    let definitions = schema
+        .get("definitions")
+        .or_else(|| schema.get("$defs"))
+        .and_then(Value::as_object);
+
+    let Some(definitions) = definitions else {
+        return (objects, enums);
+    };
+
+    for (name, def) in definitions {
+        if let Some(mappings) = enum_mappings(def) {
+            enums.push(EnumSpec {
+                name: name.clone(),
+                mappings,
+            });
+            continue;
+        }
+
+        if def.get("type").and_then(Value::as_str) == Some("object") {
+            objects.push(ObjectSpec {
+                name: name.clone(),
+                attributes: object_attributes(def),
+            });
+        }
+    }
+
+    (objects, enums)
+}
+
+/// Returns the variant mappings for a schema node declaring a string `enum`,
+/// or `None` if the node isn't an enum.
+fn enum_mappings(def: &Value) -> Option<BTreeMap<String, String>> {
+    let values = def.get("enum")?.as_array()?;
+    Some(
+        values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|value| (value.to_string(), value.to_string()))
+            .collect(),
+    )
+}
+
+/// Converts a JSON Schema object node's `properties` into [`AttrSpec`]s,
+/// consulting the node's `required` array to decide which are non-`Option`.
+fn object_attributes(def: &Value) -> Vec<AttrSpec> {
+    let required: HashSet<&str> = def
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let Some(properties) = def.get("properties").and_then(Value::as_object) else {
+        return vec![];
+    };
+
+    properties
+        .iter()
+        .map(|(name, property)| property_to_attr(name, property, required.contains(name.as_str())))
+        .collect()
+}
+
+/// Converts a single JSON Schema `properties` entry into an [`AttrSpec`],
+/// unwrapping `"type": "array"` / `items` and resolving `$ref` to the
+/// referenced definition's name.
+fn property_to_attr(name: &str, property: &Value, required: bool) -> AttrSpec {
+    let is_array = property.get("type").and_then(Value::as_str) == Some("array");
+    let scalar = if is_array {
+        property.get("items").unwrap_or(property)
+    } else {
+        property
+    };
+
+    let dtype = if let Some(reference) = scalar.get("$ref").and_then(Value::as_str) {
+        reference
+            .rsplit('/')
+            .next()
+            .unwrap_or(reference)
+            .to_string()
+    } else {
+        scalar
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("string")
+            .to_string()
+    };
+
+    AttrSpec {
+        name: name.to_string(),
+        dtypes: vec![dtype],
+        is_array,
+        required,
+        default: property.get("default").map(json_value_to_expr),
+        minimum: property.get("minimum").and_then(Value::as_f64),
+        maximum: property.get("maximum").and_then(Value::as_f64),
+        min_length: property
+            .get("minLength")
+            .and_then(Value::as_u64)
+            .map(|n| n as usize),
+        max_length: property
+            .get("maxLength")
+            .and_then(Value::as_u64)
+            .map(|n| n as usize),
+        min_items: property
+            .get("minItems")
+            .and_then(Value::as_u64)
+            .map(|n| n as usize),
+        max_items: property
+            .get("maxItems")
+            .and_then(Value::as_u64)
+            .map(|n| n as usize),
+        pattern: property
+            .get("pattern")
+            .and_then(Value::as_str)
+            .map(String::from),
+    }
+}
+
+/// Renders a JSON value as the Rust expression source expected by
+/// `#[builder(default = "...")]` (e.g. `"mol/L"` for a JSON string, `42` for a number).
+fn json_value_to_expr(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("{:?}", s),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use mdmodels_macro::parse_jsonschema;
+    use std::str::FromStr;
+
+    // Exercises every base type this request added: date/datetime -> chrono,
+    // decimal -> rust_decimal (including its min/max validation branch),
+    // bytes -> base64-encoded Vec<u8>, uri -> url::Url.
+    parse_jsonschema!("tests/data/builtin_types_schema.json");
+
+    fn sample() -> builtin_types::BuiltinTypes {
+        builtin_types::BuiltinTypes::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&chrono::Utc),
+            rust_decimal::Decimal::from_str("42.5").unwrap(),
+            vec![1, 2, 3],
+            url::Url::parse("https://example.com").unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_builtin_types_construct_and_validate() {
+        let object = sample();
+        assert!(object.validate().is_ok());
+    }
+
+    #[test]
+    fn test_decimal_range_is_validated() {
+        let mut object = sample();
+        object.amount = rust_decimal::Decimal::from_str("150").unwrap();
+
+        let errors = object.validate().expect_err("150 exceeds the maximum of 100");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "amount");
+    }
+
+    #[test]
+    fn test_bytes_round_trip_through_base64() {
+        let object = sample();
+        let value = serde_json::to_value(&object).expect("failed to serialize");
+        assert_eq!(value["payload"], "AQID"); // base64("\x01\x02\x03")
+
+        let round_tripped: builtin_types::BuiltinTypes =
+            serde_json::from_value(value).expect("failed to deserialize");
+        assert_eq!(round_tripped.payload, vec![1, 2, 3]);
+    }
+}
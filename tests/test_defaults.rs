@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use mdmodels_macro::parse_jsonschema;
+
+    // `count` carries a model-declared default, and `mass` is only a valid
+    // Rust type via the `types = { ... }` override below.
+    parse_jsonschema!("tests/data/defaults_schema.json", types = { "mass": "f64" });
+
+    #[test]
+    fn test_validate_reports_constraint_violations() {
+        let defaults = defaults::Defaults::new("ab".to_string(), 7, 1.0);
+        let errors = defaults
+            .validate()
+            .expect_err("label shorter than minLength should fail validation");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "label");
+    }
+
+    #[test]
+    fn test_default_honors_model_declared_default() {
+        // `new()` always takes every required field explicitly...
+        let built = defaults::Defaults::new("abc".to_string(), 42, 1.0);
+        assert_eq!(built.count, 42);
+
+        // ...but a field left out of a struct-update falls back to the
+        // model's declared default rather than `count`'s type default (0).
+        let defaulted = defaults::Defaults {
+            mass: 2.5,
+            ..Default::default()
+        };
+        assert_eq!(defaulted.count, 7);
+        assert_eq!(defaulted.label, String::default());
+    }
+
+    #[test]
+    fn test_types_override_maps_to_the_registered_rust_type() {
+        // `mass` isn't one of the built-in model types; it only resolves to
+        // a real Rust field because of the `types = { "mass": "f64" }`
+        // override passed to the macro above.
+        let defaults = defaults::Defaults::new("abc".to_string(), 1, 2.5);
+        assert_eq!(defaults.mass, 2.5_f64);
+    }
+}
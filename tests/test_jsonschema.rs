@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use mdmodels_macro::parse_jsonschema;
+
+    // A root-level object schema (no `definitions`/`$defs`), the common shape
+    // for a hand-written Draft-07 schema.
+    parse_jsonschema!("tests/data/schema.json");
+
+    #[test]
+    fn test_root_schema_generates_struct() {
+        let object = test::Test::new("ab".to_string(), 5);
+        assert_eq!(object.name, "ab");
+        assert_eq!(object.count, 5);
+        assert!(object.validate().is_ok());
+    }
+
+    #[test]
+    fn test_root_schema_validates_constraints() {
+        let object = test::Test::new("a".to_string(), 101);
+        let errors = object.validate().expect_err("expected constraint violations");
+        assert_eq!(errors.len(), 2);
+    }
+}
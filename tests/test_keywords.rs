@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use mdmodels_macro::parse_jsonschema;
+
+    // `type` is sanitized to a raw identifier, `self` can't be raw so it's
+    // suffixed instead, and `rename_all` exercises the container-level case
+    // conversion for every field that isn't explicitly renamed.
+    parse_jsonschema!("tests/data/keywords_schema.json", rename_all = "camelCase");
+
+    #[test]
+    fn test_reserved_keyword_fields_round_trip_under_their_original_name() {
+        let keywords = keywords::Keywords::new("t".to_string(), "s".to_string(), "m".to_string());
+
+        let value = serde_json::to_value(&keywords).expect("failed to serialize");
+        assert_eq!(value["type"], "t");
+        assert_eq!(value["self"], "s");
+        assert_eq!(value["multiWordName"], "m");
+
+        let round_tripped: keywords::Keywords =
+            serde_json::from_value(value).expect("failed to deserialize");
+        assert_eq!(round_tripped.r#type, "t");
+        assert_eq!(round_tripped.self_, "s");
+        assert_eq!(round_tripped.multi_word_name, "m");
+    }
+}
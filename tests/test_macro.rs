@@ -108,4 +108,23 @@ mod tests {
         );
         assert_eq!(object.enum_value.unwrap(), test::SomeEnum::Value);
     }
+
+    #[test]
+    fn test_enum_from_str() {
+        // Parse from the mapped wire value
+        assert_eq!(
+            "value".parse::<test::SomeEnum>().unwrap(),
+            test::SomeEnum::Value
+        );
+
+        // Parse from the variant's camel-cased name as a fallback
+        assert_eq!(
+            test::SomeEnum::try_from("Value").unwrap(),
+            test::SomeEnum::Value
+        );
+
+        // Unknown input reports the offending string
+        let err = "not-a-variant".parse::<test::SomeEnum>().unwrap_err();
+        assert_eq!(err.0, "not-a-variant");
+    }
 }
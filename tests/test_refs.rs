@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use mdmodels_macro::parse_jsonschema;
+
+    // Exercises the parts of the root-object fix that a flat scalar schema
+    // can't: a `$ref`'d nested object, `"type": "array"` + `items` of a
+    // `$ref`, and a string `enum` declared in `$defs`.
+    parse_jsonschema!("tests/data/refs_schema.json");
+
+    #[test]
+    fn test_ref_array_and_enum_from_defs() {
+        let parent = parent::Parent::new(
+            parent::Child::new("kid".to_string()),
+            vec![
+                parent::Child::new("a".to_string()),
+                parent::Child::new("b".to_string()),
+            ],
+            parent::Status::default(),
+        );
+
+        assert_eq!(parent.child.name, "kid");
+        assert_eq!(parent.children.len(), 2);
+        assert_eq!(parent.status, parent::Status::Active);
+        assert!(parent.validate().is_ok());
+    }
+}